@@ -4,7 +4,13 @@
 //! solve this problem by caching each return value. It will only, unless 
 //! explicitly called to run multiple times or if the value isn't cached, be called once.
 
-use std::{collections::HashMap, hash::Hash};
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap, HashMap},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
 
 /// This trait provides core functionality
 /// of a function cacher:
@@ -95,20 +101,221 @@ where
     // fn cache_not_if<T: Clone, CondFunc: Fn(T) -> bool>(&mut self, arg: T, condition: CondFunc, func: IFunc);
 }
 
+/// Abstraction over the map that backs an [`ICacher`].
+///
+/// It is implemented out of the box for [`HashMap`] (the default) and
+/// [`BTreeMap`]. A [`BTreeMap`] backend lets the cache key on types that
+/// are only [`Ord`] rather than [`Hash`] and keeps entries in key order,
+/// which is handy for ordered inspection and range-based eviction. The
+/// trait is public so downstream crates can plug in their own containers.
+pub trait CacheStore<K, V> {
+    /// Borrows the value stored under `key`, if any.
+    fn get(&self, key: &K) -> Option<&V>;
+
+    /// Mutably borrows the value stored under `key`, if any.
+    fn get_mut(&mut self, key: &K) -> Option<&mut V>;
+
+    /// Inserts `value` under `key`, returning the previous value if the
+    /// key was already present.
+    fn insert(&mut self, key: K, value: V) -> Option<V>;
+
+    /// Removes and returns the value stored under `key`, if any.
+    fn remove(&mut self, key: &K) -> Option<V>;
+
+    /// Returns `true` if the store holds an entry for `key`.
+    fn contains_key(&self, key: &K) -> bool;
+
+    /// Removes every entry from the store.
+    fn clear(&mut self);
+
+    /// Returns the number of entries in the store.
+    fn len(&self) -> usize;
+
+    /// Returns `true` when the store holds no entries.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Borrows up to `limit` entries for sampling-based eviction, starting
+    /// near `offset` entries into the backend's iteration order (wrapping
+    /// around). Varying `offset` between calls spreads the sampled window
+    /// across the whole store instead of always inspecting the same
+    /// leading entries.
+    fn sample(&self, offset: usize, limit: usize) -> Vec<(&K, &V)>;
+
+    /// Drops every entry for which `keep` returns `false`, used to purge
+    /// expired entries.
+    fn retain(&mut self, keep: &mut dyn FnMut(&K, &V) -> bool);
+}
+
+impl<K, V> CacheStore<K, V> for HashMap<K, V>
+where
+    K: Hash + Eq,
+{
+    #[inline]
+    fn get(&self, key: &K) -> Option<&V> {
+        HashMap::get(self, key)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        HashMap::get_mut(self, key)
+    }
+
+    #[inline]
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        HashMap::insert(self, key, value)
+    }
+
+    #[inline]
+    fn remove(&mut self, key: &K) -> Option<V> {
+        HashMap::remove(self, key)
+    }
+
+    #[inline]
+    fn contains_key(&self, key: &K) -> bool {
+        HashMap::contains_key(self, key)
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        HashMap::clear(self)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+
+    #[inline]
+    fn sample(&self, offset: usize, limit: usize) -> Vec<(&K, &V)> {
+        let len = self.len();
+        if len == 0 {
+            return Vec::new();
+        }
+        self.iter()
+            .cycle()
+            .skip(offset % len)
+            .take(limit.min(len))
+            .collect()
+    }
+
+    #[inline]
+    fn retain(&mut self, keep: &mut dyn FnMut(&K, &V) -> bool) {
+        HashMap::retain(self, |key, value| keep(key, value))
+    }
+}
+
+impl<K, V> CacheStore<K, V> for BTreeMap<K, V>
+where
+    K: Ord,
+{
+    #[inline]
+    fn get(&self, key: &K) -> Option<&V> {
+        BTreeMap::get(self, key)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        BTreeMap::get_mut(self, key)
+    }
+
+    #[inline]
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        BTreeMap::insert(self, key, value)
+    }
+
+    #[inline]
+    fn remove(&mut self, key: &K) -> Option<V> {
+        BTreeMap::remove(self, key)
+    }
+
+    #[inline]
+    fn contains_key(&self, key: &K) -> bool {
+        BTreeMap::contains_key(self, key)
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        BTreeMap::clear(self)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        BTreeMap::len(self)
+    }
+
+    #[inline]
+    fn sample(&self, offset: usize, limit: usize) -> Vec<(&K, &V)> {
+        let len = self.len();
+        if len == 0 {
+            return Vec::new();
+        }
+        self.iter()
+            .cycle()
+            .skip(offset % len)
+            .take(limit.min(len))
+            .collect()
+    }
+
+    #[inline]
+    fn retain(&mut self, keep: &mut dyn FnMut(&K, &V) -> bool) {
+        BTreeMap::retain(self, |key, value| keep(key, value))
+    }
+}
+
+/// A single cached entry: the stored return value together with the
+/// "tick" of the last time it was accessed.
+///
+/// The tick is a snapshot of the owning [`ICacher`]'s monotonically
+/// increasing counter, bumped on every hit and insert. It is used by the
+/// sampling-based eviction to approximate least-recently-used ordering
+/// without the bookkeeping of a linked list.
+///
+/// This type only appears in the backing-store type parameter of
+/// [`ICacher`]; it is not meant to be named directly.
+#[doc(hidden)]
+#[derive(Debug, Clone)]
+pub struct Entry<IReturn> {
+    value: IReturn,
+    tick: u64,
+    /// When this entry was inserted (or last overwritten). Used to decide
+    /// whether the entry has outlived the cache's time-to-live.
+    inserted: Instant,
+}
+
 /// The built-in, default, generic type for caching functions and
-/// storing its value in a [`HashMap`].
+/// storing its value in a backing store.
+///
+/// The store defaults to a [`HashMap`], which is the right choice for the
+/// common case. Select a different backend — such as a [`BTreeMap`] for
+/// [`Ord`]-only keys — by naming it in the `S` type parameter and
+/// constructing with [`with_store`](Self::with_store). Any type that
+/// implements [`CacheStore`] can be used.
 #[derive(Debug, Clone)]
-pub struct ICacher<IFunc, IType, IReturn>
+pub struct ICacher<IFunc, IType, IReturn, S = HashMap<IType, Entry<IReturn>>>
 where
     IFunc: Fn(IType) -> IReturn,
-    IType: Clone + Hash + Eq,
+    IType: Clone,
     IReturn: Clone,
+    S: CacheStore<IType, Entry<IReturn>>,
 {
     func: IFunc,
-    values: HashMap<IType, IReturn>,
+    values: S,
+    /// Optional hard limit on the number of entries. When reached, the
+    /// next insert evicts a sampled least-recently-used entry.
+    max_capacity: Option<usize>,
+    /// Monotonic access counter stamped onto entries on hit and insert.
+    tick: u64,
+    /// Optional time-to-live. Entries older than this are treated as
+    /// misses and recomputed on access.
+    ttl: Option<Duration>,
+    /// Ties the key and return types to the struct; the store only names
+    /// them through its own type parameters.
+    _marker: PhantomData<fn(IType) -> IReturn>,
 }
 
-impl<IFunc, IType, IReturn> ICacher<IFunc, IType, IReturn>
+impl<IFunc, IType, IReturn> ICacher<IFunc, IType, IReturn, HashMap<IType, Entry<IReturn>>>
 where
     IFunc: Fn(IType) -> IReturn,
     IType: Clone + Hash + Eq,
@@ -120,13 +327,13 @@ where
     ///
     /// # Notes
     /// * Use the `()` type if you do not want to return
-    ///  anything.
+    ///   anything.
     /// * If you need to have multiple parameters, enclose
-    /// them in a tuple.
+    ///   them in a tuple.
     /// * You can set a capacity of the HashMap: this means that
-    /// the HashMap will be able to hold a certain amount of elements
-    /// without reallocating. This is memory efficient as reallocating
-    /// too much can slow the program and use too much memory.  
+    ///   the HashMap will be able to hold a certain amount of elements
+    ///   without reallocating. This is memory efficient as reallocating
+    ///   too much can slow the program and use too much memory.
     ///
     /// # Example
     /// Caches a closure with 2 arguments, enclosed in a
@@ -142,6 +349,253 @@ where
         ICacher {
             func,
             values: HashMap::with_capacity(capacity.unwrap_or_default()),
+            max_capacity: None,
+            tick: 0,
+            ttl: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a new [`ICacher`] that holds at most `max` entries.
+    ///
+    /// Unlike the capacity passed to [`ICacher::new`] — which only
+    /// pre-reserves space and lets the map grow without bound — `max` is a
+    /// hard limit. Once it is reached, every [`with_arg`](Self::with_arg)
+    /// miss evicts an existing entry before inserting the new one.
+    ///
+    /// Eviction approximates least-recently-used: each entry records the
+    /// tick of its last access, and when room is needed a small, random
+    /// sample of entries is inspected and the one with the smallest tick
+    /// is dropped. This keeps the crate dependency-free and avoids the
+    /// overhead of maintaining a full LRU ordering.
+    ///
+    /// # Example
+    /// ```
+    /// use icacher::ICacher;
+    ///
+    /// let mut adder = ICacher::with_max_capacity(|(a, b): (i32, i32)| a + b, 2);
+    ///
+    /// adder.with_arg((1, 1));
+    /// adder.with_arg((2, 2));
+    /// adder.with_arg((3, 3)); // evicts a sampled entry
+    ///
+    /// assert!(adder.len() <= 2);
+    /// ```
+    #[inline]
+    pub fn with_max_capacity(func: IFunc, max: usize) -> Self {
+        ICacher {
+            func,
+            values: HashMap::with_capacity(max),
+            max_capacity: Some(max),
+            tick: 0,
+            ttl: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a new [`ICacher`] whose entries expire after `dur`.
+    ///
+    /// Each cached value remembers when it was inserted. Once an entry is
+    /// older than `dur`, [`with_arg`](Self::with_arg) treats it as a miss:
+    /// the closure is re-run and the value and timestamp are overwritten.
+    /// Expired entries also report as absent from
+    /// [`is_cached`](Self::is_cached) and can be dropped eagerly with
+    /// [`purge_expired`](Self::purge_expired).
+    ///
+    /// # Example
+    /// ```
+    /// use icacher::ICacher;
+    /// use std::time::Duration;
+    ///
+    /// let mut reader = ICacher::with_ttl(|path: &'static str| path.len(), Duration::from_secs(30));
+    /// let _ = reader.with_arg("config.toml");
+    /// ```
+    #[inline]
+    pub fn with_ttl(func: IFunc, dur: Duration) -> Self {
+        ICacher {
+            func,
+            values: HashMap::new(),
+            max_capacity: None,
+            tick: 0,
+            ttl: Some(dur),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a [`HashingICacher`], the opt-in variant that memoizes the
+    /// hash of each key.
+    ///
+    /// Reach for this when `IType` is expensive to hash; see
+    /// [`HashingICacher`] for the details. The resulting cacher exposes
+    /// the same [`with_arg`](HashingICacher::with_arg) semantics but
+    /// hashes each key only once per call.
+    ///
+    /// # Example
+    /// ```
+    /// use icacher::ICacher;
+    ///
+    /// let mut lengths = ICacher::new_hashing_keys(|s: String| s.len());
+    /// assert_eq!(lengths.with_arg("a long, expensive-to-hash key".to_string()), 29);
+    /// ```
+    #[inline]
+    pub fn new_hashing_keys(func: IFunc) -> HashingICacher<IFunc, IType, IReturn> {
+        HashingICacher::new(func)
+    }
+
+    /// Returns a borrow of the cached value for `arg`, computing and
+    /// inserting it on a miss.
+    ///
+    /// Unlike [`with_arg`](Self::with_arg), a hit returns a reference
+    /// straight out of the map with zero clones — not of the key, and not
+    /// of the value, which is the right choice when `IReturn` is large (a
+    /// big `Vec` or `String`) and the caller only needs to read it. Only a
+    /// miss clones the key, and then twice: once to pass to the closure and
+    /// once to store through the [`HashMap`] entry API. Expired
+    /// entries are recomputed in place, just like
+    /// [`with_arg`](Self::with_arg).
+    ///
+    /// # Example
+    /// ```
+    /// use icacher::ICacher;
+    ///
+    /// let mut greeter = ICacher::new(|name: String| format!("hi {name}"), None);
+    /// assert_eq!(greeter.get_ref(&"ann".to_string()), "hi ann");
+    /// ```
+    #[inline]
+    pub fn get_ref(&mut self, arg: &IType) -> &IReturn {
+        use std::collections::hash_map::Entry as MapEntry;
+
+        // Evicting needs its own lookups, so only pay for them when a hard
+        // capacity is actually configured.
+        if self.max_capacity.is_some() && self.is_full() && !self.values.contains_key(arg) {
+            self.evict_one();
+        }
+
+        self.tick += 1;
+        let tick = self.tick;
+        let ttl = self.ttl;
+
+        let hit = match self.values.get(arg) {
+            Some(entry) => match ttl {
+                Some(ttl) => entry.inserted.elapsed() < ttl,
+                None => true,
+            },
+            None => false,
+        };
+
+        if hit {
+            let entry = self.values.get_mut(arg).unwrap();
+            entry.tick = tick;
+            return &entry.value;
+        }
+
+        // Only a miss (including an expired entry) reaches here; the key is
+        // cloned twice, once for the closure and once for `entry()`.
+        let value = (self.func)(arg.clone());
+        match self.values.entry(arg.clone()) {
+            MapEntry::Occupied(mut slot) => {
+                *slot.get_mut() = Entry {
+                    value,
+                    tick,
+                    inserted: Instant::now(),
+                };
+                &slot.into_mut().value
+            }
+            MapEntry::Vacant(slot) => {
+                &slot
+                    .insert(Entry {
+                        value,
+                        tick,
+                        inserted: Instant::now(),
+                    })
+                    .value
+            }
+        }
+    }
+
+    /// Like [`get_ref`](Self::get_ref), but takes the argument by value so
+    /// a miss can move it straight in as the key instead of cloning it.
+    ///
+    /// On a hit the argument is simply dropped; it is "used" — consumed as
+    /// the inserted key — only when needed. The value is never cloned, and
+    /// an expired entry is recomputed in place.
+    #[inline]
+    pub fn get_or_insert_with(&mut self, arg: IType) -> &IReturn {
+        use std::collections::hash_map::Entry as MapEntry;
+
+        if self.max_capacity.is_some() && self.is_full() && !self.values.contains_key(&arg) {
+            self.evict_one();
+        }
+
+        self.tick += 1;
+        let tick = self.tick;
+        let ttl = self.ttl;
+        let func = &self.func;
+
+        match self.values.entry(arg) {
+            MapEntry::Occupied(mut slot) => {
+                let expired = match ttl {
+                    Some(ttl) => slot.get().inserted.elapsed() >= ttl,
+                    None => false,
+                };
+                if expired {
+                    *slot.get_mut() = Entry {
+                        value: func(slot.key().clone()),
+                        tick,
+                        inserted: Instant::now(),
+                    };
+                } else {
+                    slot.get_mut().tick = tick;
+                }
+                &slot.into_mut().value
+            }
+            MapEntry::Vacant(slot) => {
+                let value = func(slot.key().clone());
+                &slot
+                    .insert(Entry {
+                        value,
+                        tick,
+                        inserted: Instant::now(),
+                    })
+                    .value
+            }
+        }
+    }
+}
+
+impl<IFunc, IType, IReturn, S> ICacher<IFunc, IType, IReturn, S>
+where
+    IFunc: Fn(IType) -> IReturn,
+    IType: Clone,
+    IReturn: Clone,
+    S: CacheStore<IType, Entry<IReturn>>,
+{
+    /// Creates a new [`ICacher`] backed by a custom store `S`.
+    ///
+    /// The store starts empty, with no capacity limit or TTL. Use this to
+    /// choose a backend other than the default [`HashMap`] — for instance
+    /// a [`BTreeMap`], which accepts keys that are only [`Ord`]:
+    ///
+    /// ```
+    /// use icacher::ICacher;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut cacher: ICacher<_, (i32, i32), i32, BTreeMap<_, _>> =
+    ///     ICacher::with_store(|(a, b)| a + b);
+    /// assert_eq!(cacher.with_arg((20, 30)), 50);
+    /// ```
+    #[inline]
+    pub fn with_store(func: IFunc) -> Self
+    where
+        S: Default,
+    {
+        ICacher {
+            func,
+            values: S::default(),
+            max_capacity: None,
+            tick: 0,
+            ttl: None,
+            _marker: PhantomData,
         }
     }
 
@@ -154,19 +608,36 @@ where
     /// ```
     /// use icacher::ICacher;
     ///
-    /// let mut adder = ICacher::new(|(a, b)| a + b, 1);
+    /// let mut adder = ICacher::new(|(a, b)| a + b, Some(1));
     /// let value = adder.with_arg((20, 30));
     ///
     /// assert_eq!(value, 50);
     /// ```
     #[inline]
     pub fn with_arg(&mut self, arg: IType) -> IReturn {
-        if self.values.contains_key(&arg) {
-            return self.values[&arg].clone();
+        if self.is_cached(&arg) {
+            self.tick += 1;
+            let tick = self.tick;
+            let entry = self.values.get_mut(&arg).unwrap();
+            entry.tick = tick;
+            return entry.value.clone();
         }
 
         let value = (self.func)(arg.clone());
-        self.values.insert(arg, value.clone());
+        // Only evict on a genuine miss: an expired entry still occupies its
+        // slot, so overwriting it in place does not grow the map.
+        if !self.values.contains_key(&arg) && self.is_full() {
+            self.evict_one();
+        }
+        self.tick += 1;
+        self.values.insert(
+            arg,
+            Entry {
+                value: value.clone(),
+                tick: self.tick,
+                inserted: Instant::now(),
+            },
+        );
         value
     }
 
@@ -195,9 +666,40 @@ where
     }
 
     /// Checks if a function's result is cached.
+    ///
+    /// For a cache created with [`with_ttl`](Self::with_ttl), an entry that
+    /// has outlived its time-to-live reports as not cached even though it
+    /// has not yet been physically removed.
     #[inline]
     pub fn is_cached(&self, arg: &IType) -> bool {
-        self.values.contains_key(&arg)
+        match self.values.get(arg) {
+            Some(entry) => !self.is_expired(entry),
+            None => false,
+        }
+    }
+
+    /// Returns `true` if the entry has outlived the configured TTL. Always
+    /// `false` when no TTL is set.
+    #[inline]
+    fn is_expired(&self, entry: &Entry<IReturn>) -> bool {
+        match self.ttl {
+            Some(ttl) => entry.inserted.elapsed() >= ttl,
+            None => false,
+        }
+    }
+
+    /// Drops every entry that has outlived the configured TTL.
+    ///
+    /// This is a no-op for caches without a TTL. Use it to reclaim memory
+    /// eagerly instead of waiting for stale keys to be touched again.
+    #[inline]
+    pub fn purge_expired(&mut self) {
+        let ttl = match self.ttl {
+            Some(ttl) => ttl,
+            None => return,
+        };
+        self.values
+            .retain(&mut |_, entry: &Entry<IReturn>| entry.inserted.elapsed() < ttl);
     }
 
     /// Removes a function's result and returns the result if it were found.
@@ -220,9 +722,53 @@ where
     /// ```
     #[inline]
     pub fn remove_cache(&mut self, arg: IType) -> Option<IReturn> {
-        match self.values.remove(&arg) {
-            Some(val) => Some(val),
-            None => None,
+        self.values.remove(&arg).map(|entry| entry.value)
+    }
+
+    /// Returns the number of entries currently cached.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if there are no cached entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns `true` if a maximum capacity was set and the cache has
+    /// reached it, meaning the next miss will evict an entry.
+    ///
+    /// Always returns `false` for caches created with [`ICacher::new`],
+    /// which have no hard limit.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        match self.max_capacity {
+            Some(max) => self.values.len() >= max,
+            None => false,
+        }
+    }
+
+    /// Evicts one entry chosen by sampling: a handful of entries are
+    /// inspected and the least recently accessed (smallest tick) is
+    /// dropped. The sample comes from [`CacheStore::sample`], whose order
+    /// is left to the backend; the offset is derived from the current
+    /// tick so repeated evictions slide across the whole store instead of
+    /// always inspecting the same leading entries, keeping eviction cheap
+    /// and the crate dependency-free.
+    fn evict_one(&mut self) {
+        const SAMPLE: usize = 5;
+
+        let victim = self
+            .values
+            .sample(self.tick as usize, SAMPLE)
+            .into_iter()
+            .min_by_key(|(_, entry)| entry.tick)
+            .map(|(key, _)| key.clone());
+
+        if let Some(key) = victim {
+            self.values.remove(&key);
         }
     }
 
@@ -242,7 +788,7 @@ where
     /// # Example
     /// ```
     /// use icacher::ICacher;
-    /// let mut adder = ICacher::new(|(a, b): (i32, i32)| a + b, 1);
+    /// let mut adder = ICacher::new(|(a, b): (i32, i32)| a + b, Some(1));
     ///
     /// let a = 10;
     /// let b = 10;
@@ -266,18 +812,318 @@ where
         }
 
         self.void(arg);
-        return true;
+        true
+    }
+}
+
+/// Generates the `with_argsN` convenience methods.
+///
+/// Each entry of the invocation produces an [`ICacher`] impl for a tuple key of the given
+/// arity and a method that packs its positional arguments into that tuple
+/// before delegating to [`with_arg`](ICacher::with_arg). This keeps call
+/// sites reading as `cacher.with_args2(20, 30)` while the cache still
+/// keys on the argument tuple under the hood.
+///
+/// There is no generated `with_args1`: a single-argument closure already
+/// keys on its own argument type with no tuple wrapping needed, so
+/// [`with_arg`](ICacher::with_arg) already is the arity-1 form. Arities
+/// 2..=8 are generated below.
+macro_rules! impl_with_args {
+    ($($name:ident, $doc:literal => ($($gen:ident : $arg:ident),+ $(,)?));+ $(;)?) => {
+        $(
+            impl<IFunc, IReturn, S, $($gen),+> ICacher<IFunc, ($($gen,)+), IReturn, S>
+            where
+                IFunc: Fn(($($gen,)+)) -> IReturn,
+                $($gen: Clone,)+
+                IReturn: Clone,
+                S: CacheStore<($($gen,)+), Entry<IReturn>>,
+            {
+                #[doc = $doc]
+                #[inline]
+                #[allow(clippy::too_many_arguments)]
+                pub fn $name(&mut self, $($arg: $gen),+) -> IReturn {
+                    self.with_arg(($($arg,)+))
+                }
+            }
+        )+
+    };
+}
+
+impl_with_args! {
+    with_args2, "Caches a two-argument closure without wrapping the arguments in a tuple by hand." => (A: a, B: b);
+    with_args3, "Caches a three-argument closure without wrapping the arguments in a tuple by hand." => (A: a, B: b, C: c);
+    with_args4, "Caches a four-argument closure without wrapping the arguments in a tuple by hand." => (A: a, B: b, C: c, D: d);
+    with_args5, "Caches a five-argument closure without wrapping the arguments in a tuple by hand." => (A: a, B: b, C: c, D: d, E: e);
+    with_args6, "Caches a six-argument closure without wrapping the arguments in a tuple by hand." => (A: a, B: b, C: c, D: d, E: e, F: f);
+    with_args7, "Caches a seven-argument closure without wrapping the arguments in a tuple by hand." => (A: a, B: b, C: c, D: d, E: e, F: f, G: g);
+    with_args8, "Caches an eight-argument closure without wrapping the arguments in a tuple by hand." => (A: a, B: b, C: c, D: d, E: e, F: f, G: g, H: h);
+}
+
+/// The number of shards a [`SyncICacher`] is split into when none is
+/// given explicitly. A power of two keeps contention low for the common
+/// case without wasting memory on idle locks.
+const DEFAULT_SHARDS: usize = 16;
+
+/// A thread-safe cacher that can be shared behind a plain `&` reference.
+///
+/// [`ICacher`] needs `&mut self` for every lookup, so it cannot be shared
+/// between threads. `SyncICacher` instead stores its entries in several
+/// independently locked shards: a key is routed to a shard by its hash,
+/// so threads only contend when they touch the same shard. Lookups take
+/// `&self` and return a cloned value, and the closure is guaranteed to
+/// run at most once per key even when several threads race on it.
+pub struct SyncICacher<IFunc, IType, IReturn>
+where
+    IFunc: Fn(IType) -> IReturn,
+    IType: Clone + Hash + Eq,
+    IReturn: Clone,
+{
+    func: IFunc,
+    shards: Vec<Mutex<HashMap<IType, IReturn>>>,
+}
+
+impl<IFunc, IType, IReturn> SyncICacher<IFunc, IType, IReturn>
+where
+    IFunc: Fn(IType) -> IReturn,
+    IType: Clone + Hash + Eq,
+    IReturn: Clone,
+{
+    /// Creates a new [`SyncICacher`] with the default number of shards.
+    ///
+    /// # Example
+    /// ```
+    /// use icacher::SyncICacher;
+    ///
+    /// let adder = SyncICacher::new(|(a, b): (i32, i32)| a + b);
+    /// assert_eq!(adder.with_arg((20, 30)), 50);
+    /// ```
+    #[inline]
+    pub fn new(func: IFunc) -> Self {
+        Self::with_shards(func, DEFAULT_SHARDS)
+    }
+
+    /// Creates a new [`SyncICacher`] split into `shards` buckets.
+    ///
+    /// More shards reduce the chance that two threads block on the same
+    /// lock, at the cost of a little more memory. `shards` is clamped to a
+    /// minimum of one.
+    #[inline]
+    pub fn with_shards(func: IFunc, shards: usize) -> Self {
+        let shards = shards.max(1);
+        let mut buckets = Vec::with_capacity(shards);
+        for _ in 0..shards {
+            buckets.push(Mutex::new(HashMap::new()));
+        }
+        SyncICacher {
+            func,
+            shards: buckets,
+        }
+    }
+
+    /// Returns the cached value for `arg`, computing and inserting it on a
+    /// miss.
+    ///
+    /// The whole check-compute-insert sequence happens while the key's
+    /// shard lock is held, so racing threads that miss on the same key
+    /// still run the closure exactly once — the loser simply observes the
+    /// value the winner cached.
+    #[inline]
+    pub fn with_arg(&self, arg: IType) -> IReturn {
+        let shard = &self.shards[self.shard_index(&arg)];
+        let mut guard = shard.lock().unwrap();
+
+        if let Some(value) = guard.get(&arg) {
+            return value.clone();
+        }
+
+        let value = (self.func)(arg.clone());
+        guard.insert(arg, value.clone());
+        value
+    }
+
+    /// Checks if a function's result is cached.
+    #[inline]
+    pub fn is_cached(&self, arg: &IType) -> bool {
+        self.shards[self.shard_index(arg)]
+            .lock()
+            .unwrap()
+            .contains_key(arg)
+    }
+
+    /// Removes a function's result and returns it if it were cached.
+    #[inline]
+    pub fn remove_cache(&self, arg: &IType) -> Option<IReturn> {
+        self.shards[self.shard_index(arg)]
+            .lock()
+            .unwrap()
+            .remove(arg)
+    }
+
+    /// Returns the total number of entries across every shard.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.lock().unwrap().len())
+            .sum()
+    }
+
+    /// Returns `true` if no shard holds any entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.shards
+            .iter()
+            .all(|shard| shard.lock().unwrap().is_empty())
+    }
+
+    /// Picks the shard a key belongs to from its hash.
+    #[inline]
+    fn shard_index(&self, key: &IType) -> usize {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        (hasher.finish() as usize) % self.shards.len()
+    }
+}
+
+/// A cache key paired with its precomputed hash code.
+///
+/// The expensive [`Hash`] of the wrapped key is computed once, when the
+/// wrapper is built, and stored alongside it. The wrapper's own [`Hash`]
+/// impl just replays that cached `u64`, so the backing [`HashMap`] never
+/// re-runs the key's hasher — only the equality check touches the key
+/// itself, and then only after the cheap hash codes already matched.
+#[derive(Debug, Clone)]
+struct Hashed<K> {
+    hash: u64,
+    key: K,
+}
+
+impl<K: Hash> Hashed<K> {
+    /// Wraps `key`, computing and caching its hash code up front.
+    #[inline]
+    fn new(key: K) -> Self {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        Hashed {
+            hash: hasher.finish(),
+            key,
+        }
+    }
+}
+
+impl<K> Hash for Hashed<K> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
+
+impl<K: PartialEq> PartialEq for Hashed<K> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.key == other.key
+    }
+}
+
+impl<K: Eq> Eq for Hashed<K> {}
+
+/// An [`ICacher`]-style cacher that memoizes the hash of each key.
+///
+/// When `IType` is expensive to hash — deeply nested structures, long
+/// strings — a plain [`ICacher`] re-hashes the key on every
+/// [`with_arg`](ICacher::with_arg) call, and twice on a miss (once to
+/// look up, once to insert). `HashingICacher` keys its map on a
+/// [`Hashed`] wrapper and goes through the entry API, so the key's hasher
+/// runs exactly once per call and that hash is reused for the insert.
+///
+/// Construct one with [`ICacher::new_hashing_keys`]. The public
+/// [`with_arg`](Self::with_arg) semantics are unchanged.
+pub struct HashingICacher<IFunc, IType, IReturn>
+where
+    IFunc: Fn(IType) -> IReturn,
+    IType: Clone + Hash + Eq,
+    IReturn: Clone,
+{
+    func: IFunc,
+    values: HashMap<Hashed<IType>, IReturn>,
+}
+
+impl<IFunc, IType, IReturn> HashingICacher<IFunc, IType, IReturn>
+where
+    IFunc: Fn(IType) -> IReturn,
+    IType: Clone + Hash + Eq,
+    IReturn: Clone,
+{
+    /// Creates a new [`HashingICacher`].
+    #[inline]
+    pub fn new(func: IFunc) -> Self {
+        HashingICacher {
+            func,
+            values: HashMap::new(),
+        }
+    }
+
+    /// Returns the cached value for `arg`, computing and inserting it on a
+    /// miss.
+    ///
+    /// The key is hashed once, when its [`Hashed`] wrapper is built, and
+    /// the resulting entry slot is reused for both the lookup and the
+    /// insert.
+    #[inline]
+    pub fn with_arg(&mut self, arg: IType) -> IReturn {
+        use std::collections::hash_map::Entry as MapEntry;
+
+        let func = &self.func;
+        match self.values.entry(Hashed::new(arg)) {
+            MapEntry::Occupied(slot) => slot.get().clone(),
+            MapEntry::Vacant(slot) => {
+                let value = func(slot.key().key.clone());
+                slot.insert(value.clone());
+                value
+            }
+        }
+    }
+
+    /// Checks if a function's result is cached.
+    #[inline]
+    pub fn is_cached(&self, arg: &IType) -> bool {
+        self.values.contains_key(&Hashed::new(arg.clone()))
+    }
+
+    /// Removes a function's result and returns it if it were cached.
+    #[inline]
+    pub fn remove_cache(&mut self, arg: &IType) -> Option<IReturn> {
+        self.values.remove(&Hashed::new(arg.clone()))
+    }
+
+    /// Clears every cached entry.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.values.clear();
+    }
+
+    /// Returns the number of entries currently cached.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if there are no cached entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
     }
 }
 
 mod __private {
     pub trait Sealed {}
 
-    impl<A, B, C> Sealed for super::ICacher<A, B, C>
+    impl<A, B, C, S> Sealed for super::ICacher<A, B, C, S>
     where
         A: Fn(B) -> C,
-        B: Clone + super::Hash + Eq,
+        B: Clone,
         C: Clone,
+        S: super::CacheStore<B, super::Entry<C>>,
     {
     }
 }  
\ No newline at end of file